@@ -0,0 +1,98 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A side in a chess game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+  White,
+  Black,
+}
+
+impl Color {
+  /// Returns the other side.
+  pub fn opposite(self) -> Color {
+    match self {
+      Color::White => Color::Black,
+      Color::Black => Color::White,
+    }
+  }
+}
+
+/// Formats the color as its FEN letter, `w` or `b`.
+impl fmt::Display for Color {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Color::White => f.write_str("w"),
+      Color::Black => f.write_str("b"),
+    }
+  }
+}
+
+/// The error returned when parsing a [`Color`] from anything but `w` or `b`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+  token: String,
+}
+
+impl fmt::Display for ParseColorError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Invalid color '{}', expected 'w' or 'b'", self.token)
+  }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses the FEN letter of a color, `w` or `b`.
+impl FromStr for Color {
+  type Err = ParseColorError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "w" => Ok(Color::White),
+      "b" => Ok(Color::Black),
+      _ => Err(ParseColorError {
+        token: s.to_string(),
+      }),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_color() {
+    assert_eq!("w".parse::<Color>(), Ok(Color::White));
+    assert_eq!("b".parse::<Color>(), Ok(Color::Black));
+  }
+
+  #[test]
+  fn reject_invalid_color() {
+    for token in ["x", "W"] {
+      let err = token.parse::<Color>().unwrap_err();
+      assert_eq!(
+        err.to_string(),
+        format!("Invalid color '{}', expected 'w' or 'b'", token)
+      );
+    }
+  }
+
+  #[test]
+  fn display_color() {
+    assert_eq!(Color::White.to_string(), "w");
+    assert_eq!(Color::Black.to_string(), "b");
+  }
+
+  #[test]
+  fn display_round_trip() {
+    for color in [Color::White, Color::Black] {
+      assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+    }
+  }
+
+  #[test]
+  fn opposite_color() {
+    assert_eq!(Color::White.opposite(), Color::Black);
+    assert_eq!(Color::Black.opposite(), Color::White);
+  }
+}
@@ -0,0 +1,69 @@
+/// The FEN of the standard chess start position, as implied by `position startpos`.
+pub const STARTPOS_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+/// Splits a FEN into its six whitespace-separated fields without validating them.
+///
+/// Returns `None` unless there are exactly six fields. This is a cheap way
+/// to peek at a single field, such as the side to move, when full
+/// validation is not needed.
+pub fn fen_fields(fen: &str) -> Option<[&str; 6]> {
+  let mut fields = fen.split_whitespace();
+  let result = [
+    fields.next()?,
+    fields.next()?,
+    fields.next()?,
+    fields.next()?,
+    fields.next()?,
+    fields.next()?,
+  ];
+
+  match fields.next() {
+    Some(_) => None,
+    None => Some(result),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::uci::Color;
+
+  #[test]
+  fn startpos_fen_is_well_formed() {
+    let fields: Vec<&str> = STARTPOS_FEN.split_whitespace().collect();
+
+    assert_eq!(fields.len(), 6);
+    assert_eq!(fields[0].split('/').count(), 8);
+    assert_eq!(fields[1].parse::<Color>(), Ok(Color::White));
+  }
+
+  #[test]
+  fn fen_fields_of_startpos() {
+    assert_eq!(
+      fen_fields(STARTPOS_FEN),
+      Some([
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR",
+        "w",
+        "KQkq",
+        "-",
+        "0",
+        "1"
+      ])
+    );
+  }
+
+  #[test]
+  fn fen_fields_with_irregular_whitespace() {
+    assert_eq!(
+      fen_fields("  8/8/8/8/8/8/8/8 \t b  -   e3 12\t34  "),
+      Some(["8/8/8/8/8/8/8/8", "b", "-", "e3", "12", "34"])
+    );
+  }
+
+  #[test]
+  fn fen_fields_with_wrong_field_count() {
+    assert_eq!(fen_fields("8/8/8/8/8/8/8/8 w - - 0"), None);
+    assert_eq!(fen_fields("8/8/8/8/8/8/8/8 w - - 0 1 extra"), None);
+    assert_eq!(fen_fields(""), None);
+  }
+}
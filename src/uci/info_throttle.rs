@@ -0,0 +1,107 @@
+use std::time::{Duration, Instant};
+
+/// Limits how often `info` lines are sent to the GUI.
+///
+/// Engines that report after every node can flood the GUI with output.
+/// The throttle lets a line through only if at least `min_interval` has
+/// passed since the last line it let through.
+#[derive(Debug, Clone)]
+pub struct InfoThrottle {
+  min_interval: Duration,
+  last_emit: Option<Instant>,
+}
+
+impl InfoThrottle {
+  /// Creates a throttle that emits at most one line per `min_interval`.
+  pub fn new(min_interval: Duration) -> Self {
+    Self {
+      min_interval,
+      last_emit: None,
+    }
+  }
+
+  /// Returns `true` if an `info` line should be emitted at `now`.
+  ///
+  /// The first call always returns `true`. A call that returns `true`
+  /// records `now` as the time of the last emission.
+  pub fn should_emit(&mut self, now: Instant) -> bool {
+    let ready = self
+      .last_emit
+      .is_none_or(|last| now.saturating_duration_since(last) >= self.min_interval);
+
+    if ready {
+      self.last_emit = Some(now);
+    }
+
+    ready
+  }
+
+  /// Forgets the last emission, so the next call to `should_emit` passes.
+  ///
+  /// Call this when a new search starts.
+  pub fn reset(&mut self) {
+    self.last_emit = None;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const INTERVAL: Duration = Duration::from_millis(100);
+
+  #[test]
+  fn first_call_passes() {
+    let mut throttle = InfoThrottle::new(INTERVAL);
+    assert!(throttle.should_emit(Instant::now()));
+  }
+
+  #[test]
+  fn call_before_interval_is_blocked() {
+    let base = Instant::now();
+    let mut throttle = InfoThrottle::new(INTERVAL);
+
+    assert!(throttle.should_emit(base));
+    assert!(!throttle.should_emit(base + Duration::from_millis(99)));
+  }
+
+  #[test]
+  fn call_at_interval_passes() {
+    let base = Instant::now();
+    let mut throttle = InfoThrottle::new(INTERVAL);
+
+    assert!(throttle.should_emit(base));
+    assert!(throttle.should_emit(base + INTERVAL));
+  }
+
+  #[test]
+  fn blocked_call_does_not_move_last_emit() {
+    let base = Instant::now();
+    let mut throttle = InfoThrottle::new(INTERVAL);
+
+    assert!(throttle.should_emit(base));
+    assert!(!throttle.should_emit(base + Duration::from_millis(60)));
+    assert!(throttle.should_emit(base + Duration::from_millis(100)));
+    assert!(!throttle.should_emit(base + Duration::from_millis(160)));
+    assert!(throttle.should_emit(base + Duration::from_millis(200)));
+  }
+
+  #[test]
+  fn earlier_timestamp_is_blocked() {
+    let base = Instant::now();
+    let mut throttle = InfoThrottle::new(INTERVAL);
+
+    assert!(throttle.should_emit(base + INTERVAL));
+    assert!(!throttle.should_emit(base));
+  }
+
+  #[test]
+  fn reset_lets_next_call_through() {
+    let base = Instant::now();
+    let mut throttle = InfoThrottle::new(INTERVAL);
+
+    assert!(throttle.should_emit(base));
+    throttle.reset();
+    assert!(throttle.should_emit(base + Duration::from_millis(1)));
+  }
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+
+/// Builds the `bestmove` line for a position without legal moves.
+///
+/// Engines send `bestmove (none)` when the root is checkmate or stalemate.
+/// It differs from the null move `0000`, which the UCI spec defines as a
+/// move that passes the turn, and which some engines send in the same
+/// situation. A GUI should treat both as "no best move".
+pub fn build_bestmove_none() -> String {
+  String::from("bestmove (none)")
+}
+
+/// The state reported by `copyprotection` and `registration` messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProtectionStatus {
+  /// The engine is still checking.
+  Checking,
+  /// The check succeeded.
+  Ok,
+  /// The check failed.
+  Error,
+}
+
+/// Formats the status as its wire keyword, e.g. `checking`.
+impl fmt::Display for ProtectionStatus {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ProtectionStatus::Checking => f.write_str("checking"),
+      ProtectionStatus::Ok => f.write_str("ok"),
+      ProtectionStatus::Error => f.write_str("error"),
+    }
+  }
+}
+
+/// Builds a `copyprotection` line, e.g. `copyprotection checking`.
+pub fn build_copyprotection_msg(status: ProtectionStatus) -> String {
+  format!("copyprotection {}", status)
+}
+
+/// Builds a `registration` line, e.g. `registration ok`.
+pub fn build_registration_msg(status: ProtectionStatus) -> String {
+  format!("registration {}", status)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn copyprotection_msg() {
+    assert_eq!(
+      build_copyprotection_msg(ProtectionStatus::Checking),
+      "copyprotection checking"
+    );
+    assert_eq!(
+      build_copyprotection_msg(ProtectionStatus::Ok),
+      "copyprotection ok"
+    );
+    assert_eq!(
+      build_copyprotection_msg(ProtectionStatus::Error),
+      "copyprotection error"
+    );
+  }
+
+  #[test]
+  fn registration_msg() {
+    assert_eq!(
+      build_registration_msg(ProtectionStatus::Checking),
+      "registration checking"
+    );
+    assert_eq!(
+      build_registration_msg(ProtectionStatus::Ok),
+      "registration ok"
+    );
+    assert_eq!(
+      build_registration_msg(ProtectionStatus::Error),
+      "registration error"
+    );
+  }
+}
@@ -0,0 +1,13 @@
+mod color;
+mod fen;
+mod info_throttle;
+mod messages;
+mod verify;
+
+pub use color::{Color, ParseColorError};
+pub use fen::{fen_fields, STARTPOS_FEN};
+pub use info_throttle::InfoThrottle;
+pub use messages::{
+  build_bestmove_none, build_copyprotection_msg, build_registration_msg, ProtectionStatus,
+};
+pub use verify::verify_bestmove_matches_pv;
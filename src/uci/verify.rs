@@ -0,0 +1,32 @@
+/// Checks that `bestmove` is the first move of the last reported PV.
+///
+/// An engine whose `bestmove` differs from the head of its final `pv`
+/// usually has a bug in how it tracks the principal variation. An empty
+/// PV has nothing to contradict, so it is treated as consistent.
+pub fn verify_bestmove_matches_pv(bestmove: &str, last_pv: &[String]) -> bool {
+  last_pv.first().is_none_or(|mv| mv == bestmove)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pv(moves: &[&str]) -> Vec<String> {
+    moves.iter().map(|mv| mv.to_string()).collect()
+  }
+
+  #[test]
+  fn matching_head() {
+    assert!(verify_bestmove_matches_pv("e2e4", &pv(&["e2e4", "e7e5"])));
+  }
+
+  #[test]
+  fn mismatching_head() {
+    assert!(!verify_bestmove_matches_pv("d2d4", &pv(&["e2e4", "e7e5"])));
+  }
+
+  #[test]
+  fn empty_pv_is_consistent() {
+    assert!(verify_bestmove_matches_pv("e2e4", &[]));
+  }
+}